@@ -44,9 +44,120 @@
 ///     }
 /// }
 /// ```
+///
+/// If you need to keep the original binding alive alongside the clone, use
+/// `as` to bind the clone to a different name. `clone!(x as y)` will
+/// generate:
+///
+/// ```
+/// # #[macro_use] extern crate clonelet;
+/// # let x = 0;
+/// let y = x.clone();
+/// ```
+///
+/// `as` can be combined with `mut` and with struct members, so
+/// `clone!(mut x as y)` and `clone!(self.x as y)` work as you'd expect.
+///
+/// There's also a block form, `clone!([x, mut y] => expr)`, for when you
+/// want to clone straight into an expression such as a `move` closure,
+/// without opening a dedicated block first:
+///
+/// ```
+/// # #[macro_use] extern crate clonelet;
+/// # let (x, y) = (0, 0);
+/// let closure = clone!([x, mut y] => move || {
+///     y += 1;
+///     x + y
+/// });
+/// ```
+///
+/// which generates:
+///
+/// ```
+/// # let (x, y) = (0, 0);
+/// let closure = {
+///     let x = x.clone();
+///     let mut y = y.clone();
+///     move || {
+///         y += 1;
+///         x + y
+///     }
+/// };
+/// ```
+///
+/// Deeper sources are supported too: multi-segment paths such as
+/// `s.inner.handle`, tuple fields such as `tuple.0`, and function calls such
+/// as `get_thing()`. Method calls on a receiver, such as `s.get()`, are not
+/// accepted. The binding name is derived automatically from the
+/// source (the trailing ident for a path, the base ident for a tuple
+/// field, and the function's ident for a call), so `clone!(s.inner.handle)`
+/// generates:
+///
+/// ```
+/// # struct Inner { handle: i32 }
+/// # struct S { inner: Inner }
+/// # let s = S { inner: Inner { handle: 0 } };
+/// let handle = s.inner.handle.clone();
+/// ```
+///
+/// When the derived name would collide with something else in scope, fall
+/// back to `as`, which this arm also accepts: `clone!(s.inner.handle as h)`.
+///
+/// For `Rc`/`Arc`, prefix the source with `&` to get an explicit pointer
+/// clone instead of `.clone()`, so teams running clippy's `clone_on_ref_ptr`
+/// lint don't have to carve out exceptions. `clone!(&foo)` generates:
+///
+/// ```
+/// # #[macro_use] extern crate clonelet;
+/// # use std::rc::Rc;
+/// # let foo = Rc::new(0);
+/// let foo = clonelet::PtrClone::ptr_clone(&foo);
+/// ```
+///
+/// which, for `Rc<T>` and `Arc<T>`, performs `Rc::clone(&foo)`/`Arc::clone(&foo)`
+/// rather than calling the inherent `.clone()` method. `mut` and struct
+/// members work here too: `clone!(mut &foo)` and `clone!(&self.foo)`.
 #[macro_export]
 macro_rules! clone{
     ($(,)?) => {};
+    ([$($list:tt)*] => $body:expr) => {
+        {
+            $crate::clone!($($list)*);
+            $body
+        }
+    };
+    (& $scope:ident . $name:ident $(, $($tail:tt)*)?) => {
+        let $name = $crate::PtrClone::ptr_clone(&$scope.$name);
+        $($crate::clone!($($tail)*);)?
+    };
+    (mut & $scope:ident . $name:ident $(, $($tail:tt)*)?) => {
+        let mut $name = $crate::PtrClone::ptr_clone(&$scope.$name);
+        $($crate::clone!($($tail)*);)?
+    };
+    (& $name:ident $(, $($tail:tt)*)?) => {
+        let $name = $crate::PtrClone::ptr_clone(&$name);
+        $($crate::clone!($($tail)*);)?
+    };
+    (mut & $name:ident $(, $($tail:tt)*)?) => {
+        let mut $name = $crate::PtrClone::ptr_clone(&$name);
+        $($crate::clone!($($tail)*);)?
+    };
+    ($scope:ident . $name:ident as $alias:ident $(, $($tail:tt)*)?) => {
+        let $alias = $scope . $name.clone();
+        $($crate::clone!($($tail)*);)?
+    };
+    (mut $scope:ident . $name:ident as $alias:ident $(, $($tail:tt)*)?) => {
+        let mut $alias = $scope . $name.clone();
+        $($crate::clone!($($tail)*);)?
+    };
+    ($name:ident as $alias:ident $(, $($tail:tt)*)?) => {
+        let $alias = $name.clone();
+        $($crate::clone!($($tail)*);)?
+    };
+    (mut $name:ident as $alias:ident $(, $($tail:tt)*)?) => {
+        let mut $alias = $name.clone();
+        $($crate::clone!($($tail)*);)?
+    };
     ($scope:ident . $name:ident $(, $($tail:tt)*)?) => {
         let $name = $scope . $name.clone();
         $($crate::clone!($($tail)*);)?
@@ -63,11 +174,113 @@ macro_rules! clone{
         let mut $name = $name.clone();
         $($crate::clone!($($tail)*);)?
     };
+    ($first:ident $(. $seg:tt)+ as $alias:ident $(, $($tail:tt)*)?) => {
+        let $alias = $first $(. $seg)+ .clone();
+        $($crate::clone!($($tail)*);)?
+    };
+    (mut $first:ident $(. $seg:tt)+ as $alias:ident $(, $($tail:tt)*)?) => {
+        let mut $alias = $first $(. $seg)+ .clone();
+        $($crate::clone!($($tail)*);)?
+    };
+    ($first:ident $(. $seg:tt)+ $(, $($tail:tt)*)?) => {
+        let $crate::__clonelet_name!($first $(. $seg)+) = $first $(. $seg)+ .clone();
+        $($crate::clone!($($tail)*);)?
+    };
+    (mut $first:ident $(. $seg:tt)+ $(, $($tail:tt)*)?) => {
+        $crate::__clonelet_let_mut!($first $(. $seg)+);
+        $($crate::clone!($($tail)*);)?
+    };
+    ($name:ident ( $($args:tt)* ) as $alias:ident $(, $($tail:tt)*)?) => {
+        let $alias = $name($($args)*).clone();
+        $($crate::clone!($($tail)*);)?
+    };
+    (mut $name:ident ( $($args:tt)* ) as $alias:ident $(, $($tail:tt)*)?) => {
+        let mut $alias = $name($($args)*).clone();
+        $($crate::clone!($($tail)*);)?
+    };
+    ($name:ident ( $($args:tt)* ) $(, $($tail:tt)*)?) => {
+        let $name = $name($($args)*).clone();
+        $($crate::clone!($($tail)*);)?
+    };
+    (mut $name:ident ( $($args:tt)* ) $(, $($tail:tt)*)?) => {
+        let mut $name = $name($($args)*).clone();
+        $($crate::clone!($($tail)*);)?
+    };
+}
+
+/// Maps a clone source &mdash; a multi-segment path, a tuple field access, or
+/// a function call &mdash; to the identifier its clone should be bound to: the
+/// trailing ident for a path, the ident immediately before a numeric tuple
+/// index, or the function's ident for a call. Not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __clonelet_name {
+    (@track $name:ident) => {
+        $name
+    };
+    (@track $name:ident, $seg:ident $(, $rest:tt)*) => {
+        $crate::__clonelet_name!(@track $seg $(, $rest)*)
+    };
+    (@track $name:ident, $seg:literal $(, $rest:tt)*) => {
+        $crate::__clonelet_name!(@track $name $(, $rest)*)
+    };
+    ($name:ident ( $($args:tt)* )) => {
+        $name
+    };
+    ($first:ident $(. $seg:tt)+) => {
+        $crate::__clonelet_name!(@track $first $(, $seg)+)
+    };
+    ($name:ident) => {
+        $name
+    };
+}
+
+/// Emits `let mut NAME = EXPR.clone();` for a deep-path/tuple-field clone
+/// source, deriving `NAME` the same way as [`__clonelet_name!`]. `mut` must
+/// directly precede a named binding, so unlike the non-`mut` case this can't
+/// be expressed as `let mut $crate::__clonelet_name!(...) = ...;` &mdash;
+/// the whole `let` statement has to come out of a single macro expansion
+/// instead. Not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __clonelet_let_mut {
+    (@track ($($expr:tt)*) $name:ident) => {
+        let mut $name = $($expr)* .clone();
+    };
+    (@track ($($expr:tt)*) $name:ident, $seg:ident $(, $rest:tt)*) => {
+        $crate::__clonelet_let_mut!(@track ($($expr)*) $seg $(, $rest)*)
+    };
+    (@track ($($expr:tt)*) $name:ident, $seg:literal $(, $rest:tt)*) => {
+        $crate::__clonelet_let_mut!(@track ($($expr)*) $name $(, $rest)*)
+    };
+    ($first:ident $(. $seg:tt)+) => {
+        $crate::__clonelet_let_mut!(@track ($first $(. $seg)+) $first $(, $seg)+)
+    };
+}
+
+/// Performs an explicit pointer clone, i.e. `Rc::clone`/`Arc::clone` rather
+/// than `.clone()`, for the `&` forms of [`clone!`]. Not part of the public
+/// API.
+#[doc(hidden)]
+pub trait PtrClone {
+    fn ptr_clone(&self) -> Self;
+}
+
+impl<T> PtrClone for ::std::rc::Rc<T> {
+    fn ptr_clone(&self) -> Self {
+        ::std::rc::Rc::clone(self)
+    }
+}
+
+impl<T> PtrClone for ::std::sync::Arc<T> {
+    fn ptr_clone(&self) -> Self {
+        ::std::sync::Arc::clone(self)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::mem;
+    use std::{mem, rc::Rc, sync::Arc};
 
     #[derive(Clone)]
     struct Test;
@@ -91,6 +304,16 @@ mod tests {
         }
     }
 
+    struct Nested {
+        inner: Scope,
+    }
+
+    struct Tuple(Test);
+
+    fn get_thing() -> Test {
+        Test
+    }
+
     #[test]
     fn basic() {
         let x = Test;
@@ -154,4 +377,200 @@ mod tests {
 
         mem::drop(x);
     }
+
+    #[test]
+    fn rename() {
+        let x = Test;
+
+        {
+            clone!(x as y);
+            mem::drop(y);
+        }
+
+        mem::drop(x);
+    }
+
+    #[test]
+    fn mutable_rename() {
+        let x = Test;
+
+        {
+            clone!(mut x as y);
+            y.mutate();
+            mem::drop(x);
+        }
+    }
+
+    #[test]
+    fn scope_rename() {
+        let x = Scope { y: Test };
+
+        {
+            clone!(x.y as z);
+            mem::drop(z);
+        }
+
+        mem::drop(x);
+    }
+
+    #[test]
+    fn mutable_scope_rename() {
+        let x = Scope { y: Test };
+
+        {
+            clone!(mut x.y as z);
+            z.mutate();
+        }
+
+        mem::drop(x);
+    }
+
+    #[test]
+    fn block_form() {
+        let x = Test;
+        let y = Test;
+
+        let closure = clone!([x, mut y] => move || {
+            y.mutate();
+            mem::drop(x);
+            mem::drop(y);
+        });
+        closure();
+
+        mem::drop(x);
+        mem::drop(y);
+    }
+
+    #[test]
+    fn multi_rename() {
+        let x = Test;
+        let y = Test;
+
+        {
+            clone!(x as x2, mut y as y2);
+            y2.mutate();
+            mem::drop(x2);
+            mem::drop(y2);
+        }
+
+        mem::drop(x);
+        mem::drop(y);
+    }
+
+    #[test]
+    fn deep_path() {
+        let x = Nested {
+            inner: Scope { y: Test },
+        };
+
+        {
+            clone!(x.inner.y);
+            mem::drop(y);
+        }
+
+        mem::drop(x);
+    }
+
+    #[test]
+    fn mutable_deep_path() {
+        let x = Nested {
+            inner: Scope { y: Test },
+        };
+
+        {
+            clone!(mut x.inner.y);
+            y.mutate();
+            mem::drop(y);
+        }
+
+        mem::drop(x);
+    }
+
+    #[test]
+    fn tuple_field() {
+        let tuple = Tuple(Test);
+
+        {
+            clone!(tuple.0);
+            mem::drop(tuple);
+        }
+
+        mem::drop(tuple);
+    }
+
+    #[test]
+    fn mutable_tuple_field() {
+        let tuple = Tuple(Test);
+
+        {
+            clone!(mut tuple.0);
+            tuple.mutate();
+            mem::drop(tuple);
+        }
+
+        mem::drop(tuple);
+    }
+
+    #[test]
+    fn call() {
+        {
+            clone!(get_thing());
+            mem::drop(get_thing);
+        }
+    }
+
+    #[test]
+    fn deep_path_rename() {
+        let x = Nested {
+            inner: Scope { y: Test },
+        };
+
+        {
+            clone!(x.inner.y as z);
+            mem::drop(z);
+        }
+
+        mem::drop(x);
+    }
+
+    #[test]
+    fn ptr_clone() {
+        let x = Rc::new(Test);
+
+        {
+            clone!(&x);
+            assert_eq!(Rc::strong_count(&x), 2);
+        }
+
+        assert_eq!(Rc::strong_count(&x), 1);
+    }
+
+    #[test]
+    fn mutable_ptr_clone() {
+        let x = Arc::new(Test);
+
+        {
+            clone!(mut &x);
+            assert_eq!(Arc::strong_count(&x), 2);
+            x = Arc::new(Test);
+            assert_eq!(Arc::strong_count(&x), 1);
+        }
+    }
+
+    #[test]
+    fn ptr_clone_scope() {
+        let x = Scope2 { y: Rc::new(Test) };
+
+        {
+            clone!(&x.y);
+            assert_eq!(Rc::strong_count(&y), 2);
+            mem::drop(y);
+        }
+
+        assert_eq!(Rc::strong_count(&x.y), 1);
+    }
+
+    struct Scope2 {
+        y: Rc<Test>,
+    }
 }